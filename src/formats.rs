@@ -0,0 +1,173 @@
+//! Pixel format markers usable with [`PixelFormat`](crate::px::PixelFormat).
+//!
+//! These are zero-sized tags that select how [`Resizer`](crate::Resizer)
+//! accumulates and finalizes pixels; they carry no data of their own.
+use core::marker::PhantomData;
+
+/// Plain RGB, accumulated and finalized in whatever colorspace the stored
+/// values already are in (no gamma handling).
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Rgb<T = u8, F = T>(PhantomData<(T, F)>);
+
+impl<T, F> Rgb<T, F> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Plain RGBA, channels accumulated independently (no premultiplication).
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Rgba<T = u8, F = T>(PhantomData<(T, F)>);
+
+impl<T, F> Rgba<T, F> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// RGBA with colors premultiplied by alpha before accumulation, which avoids
+/// dark fringes around transparent edges.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RgbaPremultiply<T = u8, F = T>(PhantomData<(T, F)>);
+
+impl<T, F> RgbaPremultiply<T, F> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Single-channel grayscale.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Gray<F = u8, T = F>(PhantomData<(F, T)>);
+
+impl<F, T> Gray<F, T> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Luma with alpha, channels accumulated independently (no
+/// premultiplication).
+#[derive(Debug, Copy, Clone, Default)]
+pub struct GrayAlpha<T = u8, F = T>(PhantomData<(T, F)>);
+
+impl<T, F> GrayAlpha<T, F> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Luma with alpha, premultiplied by alpha before accumulation, mirroring
+/// [`RgbaPremultiply`] for the two-channel case.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct GrayAlphaPremultiply<T = u8, F = T>(PhantomData<(T, F)>);
+
+impl<T, F> GrayAlphaPremultiply<T, F> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// RGB stored as sRGB-encoded (gamma) samples, decoded to linear light
+/// before weighting and re-encoded to sRGB after.
+///
+/// Use this instead of [`Rgb`] whenever the source/destination buffers hold
+/// ordinary sRGB image data (the overwhelming majority of PNG/JPEG content):
+/// averaging gamma-encoded values directly darkens bright detail and
+/// muddies thin highlights.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SrgbRgb<T = u8, F = T>(PhantomData<(T, F)>);
+
+impl<T, F> SrgbRgb<T, F> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// RGBA stored as sRGB-encoded color with a linear alpha channel.
+///
+/// Colors are decoded to linear light and premultiplied by alpha before
+/// accumulation (mirroring [`RgbaPremultiply`]), then un-premultiplied and
+/// re-encoded to sRGB on output. Alpha itself is never gamma-mapped.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SrgbRgba<T = u8, F = T>(PhantomData<(T, F)>);
+
+impl<T, F> SrgbRgba<T, F> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Single-channel sRGB-encoded grayscale, decoded to linear light before
+/// weighting and re-encoded to sRGB after.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SrgbGray<F = u8, T = F>(PhantomData<(F, T)>);
+
+impl<F, T> SrgbGray<F, T> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Sentinel for [`Channels`]'s `ALPHA` parameter meaning "no channel is an
+/// alpha channel" (plain, non-premultiplied accumulation).
+pub const NO_ALPHA: usize = usize::MAX;
+
+/// Generic `N`-channel pixel for data the built-in formats don't cover:
+/// CMYK (`N = 4`), RGBE, or arbitrary multispectral/scientific imagery.
+///
+/// `ALPHA` is the 0-indexed channel (if any) that drives premultiplication
+/// of the other `N - 1` channels, exactly like [`RgbaPremultiply`] does for
+/// RGB; pass [`NO_ALPHA`] for straight, non-premultiplied accumulation.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Channels<T = u8, F = T, const N: usize = 4, const ALPHA: usize = NO_ALPHA>(
+    PhantomData<(T, F)>,
+);
+
+impl<T, F, const N: usize, const ALPHA: usize> Channels<T, F, N, ALPHA> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Fixed-size pixel data for [`Channels`]'s output end (its input pixels
+/// are plain `[T; N]` arrays).
+///
+/// This wraps `[T; N]` rather than using the array directly because
+/// `[T; N]` only implements `Default` for specific literal lengths, not a
+/// generic const `N`, and [`PixelFormat::OutputPixel`](crate::px::PixelFormat::OutputPixel)
+/// requires `Default`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ChannelsPixel<T, const N: usize>(pub [T; N]);
+
+impl<T: Copy + Default, const N: usize> Default for ChannelsPixel<T, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self([T::default(); N])
+    }
+}
+
+impl<T, const N: usize> core::ops::Index<usize> for ChannelsPixel<T, N> {
+    type Output = T;
+    #[inline(always)]
+    fn index(&self, i: usize) -> &T {
+        &self.0[i]
+    }
+}
+
+impl<T, const N: usize> core::ops::IndexMut<usize> for ChannelsPixel<T, N> {
+    #[inline(always)]
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.0[i]
+    }
+}
@@ -1,5 +1,6 @@
 use crate::formats;
 pub use rgb::alt::Gray;
+pub use rgb::alt::GrayAlpha;
 pub use rgb::RGB;
 pub use rgb::RGBA;
 #[cfg(feature = "fp16")]
@@ -32,6 +33,33 @@ pub trait PixelFormat: Send + Sync {
     fn add_acc(acc: &mut Self::Accumulator, inp: Self::Accumulator, coeff: fpN);
     /// Finalize, convert to output pixel format
     fn into_pixel(&self, acc: Self::Accumulator) -> Self::OutputPixel;
+
+    /// Add a whole row of input pixels into the matching row of
+    /// accumulators, all weighted by the same coefficient (first axis,
+    /// batched). The default implementation just loops [`add`](Self::add);
+    /// currently only `formats::Rgba<_, u8>` overrides this with a SIMD
+    /// path (SSE2, 8-bit RGBA) — every other format, including `Rgb` and
+    /// `Gray`, still uses this scalar default.
+    #[inline(always)]
+    fn add_row(&self, acc_row: &mut [Self::Accumulator], inp_row: &[Self::InputPixel], coeff: fpN) {
+        debug_assert_eq!(acc_row.len(), inp_row.len());
+        for (acc, &inp) in acc_row.iter_mut().zip(inp_row) {
+            self.add(acc, inp, coeff);
+        }
+    }
+
+    /// Add a whole row of accumulators into another row, all weighted by the
+    /// same coefficient (second axis, batched). The default implementation
+    /// just loops [`add_acc`](Self::add_acc); no format overrides this yet,
+    /// so the vertical resize pass has no SIMD fast path regardless of
+    /// pixel format.
+    #[inline(always)]
+    fn add_acc_row(acc_row: &mut [Self::Accumulator], inp_row: &[Self::Accumulator], coeff: fpN) {
+        debug_assert_eq!(acc_row.len(), inp_row.len());
+        for (acc, &inp) in acc_row.iter_mut().zip(inp_row) {
+            Self::add_acc(acc, inp, coeff);
+        }
+    }
 }
 
 impl<F: ToFloat, T: ToFloat> PixelFormat for formats::Rgb<T, F> {
@@ -103,6 +131,36 @@ impl<F: ToFloat, T: ToFloat> PixelFormat for formats::Rgba<T, F> {
             a: T::from_float(acc.a),
         }
     }
+
+    #[inline]
+    fn add_row(&self, acc_row: &mut [RGBA<fpN>], inp_row: &[RGBA<F>], coeff: fpN) {
+        // `F`/`fpN` are fixed at compile time per monomorphization, so this
+        // `TypeId` check optimizes away to a constant; it's what lets the
+        // SSE2 fast path live in this one generic impl instead of needing
+        // unstable specialization against `formats::Rgba<u8, u8>`.
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "fp16")))]
+        {
+            use std::any::TypeId;
+            if TypeId::of::<F>() == TypeId::of::<u8>() && crate::simd::has_sse2() {
+                debug_assert_eq!(acc_row.len(), inp_row.len());
+                // Both rows are cut to their common length, like the scalar
+                // `zip` loop, so the SSE2 path sees equal lengths.
+                let len = acc_row.len().min(inp_row.len());
+                // SAFETY: the TypeId check above proves F == u8, so this
+                // slice has the same layout as `&[RGBA<u8>]`; has_sse2()
+                // confirms the CPU supports the intrinsics used below.
+                let inp_row: &[RGBA<u8>] = unsafe {
+                    core::slice::from_raw_parts(inp_row.as_ptr() as *const RGBA<u8>, len)
+                };
+                unsafe { crate::simd::add_row_rgba_u8_sse2(&mut acc_row[..len], inp_row, coeff) };
+                return;
+            }
+        }
+        debug_assert_eq!(acc_row.len(), inp_row.len());
+        for (acc, &inp) in acc_row.iter_mut().zip(inp_row) {
+            self.add(acc, inp, coeff);
+        }
+    }
 }
 
 impl<F: ToFloat, T: ToFloat> PixelFormat for formats::RgbaPremultiply<T, F> {
@@ -175,6 +233,316 @@ impl<F: ToFloat, T: ToFloat> PixelFormat for formats::Gray<F, T> {
     }
 }
 
+impl<F: ToFloat, T: ToFloat> PixelFormat for formats::GrayAlpha<T, F> {
+    type InputPixel = GrayAlpha<F>;
+    type OutputPixel = GrayAlpha<T>;
+    type Accumulator = GrayAlpha<fpN>;
+
+    #[inline(always)]
+    fn new() -> Self::Accumulator {
+        GrayAlpha::new(fpN::ZERO, fpN::ZERO)
+    }
+
+    #[inline(always)]
+    fn add(&self, acc: &mut Self::Accumulator, inp: GrayAlpha<F>, coeff: fpN) {
+        acc.v += inp.v.to_float() * coeff;
+        acc.a += inp.a.to_float() * coeff;
+    }
+
+    #[inline(always)]
+    fn add_acc(acc: &mut Self::Accumulator, inp: Self::Accumulator, coeff: fpN) {
+        acc.v += inp.v * coeff;
+        acc.a += inp.a * coeff;
+    }
+
+    #[inline(always)]
+    fn into_pixel(&self, acc: Self::Accumulator) -> GrayAlpha<T> {
+        GrayAlpha::new(T::from_float(acc.v), T::from_float(acc.a))
+    }
+}
+
+impl<F: ToFloat, T: ToFloat> PixelFormat for formats::GrayAlphaPremultiply<T, F> {
+    type InputPixel = GrayAlpha<F>;
+    type OutputPixel = GrayAlpha<T>;
+    type Accumulator = GrayAlpha<fpN>;
+
+    #[inline(always)]
+    fn new() -> Self::Accumulator {
+        GrayAlpha::new(fpN::ZERO, fpN::ZERO)
+    }
+
+    #[inline(always)]
+    fn add(&self, acc: &mut Self::Accumulator, inp: GrayAlpha<F>, coeff: fpN) {
+        let a_coeff = inp.a.to_float() * coeff;
+        acc.v += inp.v.to_float() * a_coeff;
+        acc.a += a_coeff;
+    }
+
+    #[inline(always)]
+    fn add_acc(acc: &mut Self::Accumulator, inp: Self::Accumulator, coeff: fpN) {
+        acc.v += inp.v * coeff;
+        acc.a += inp.a * coeff;
+    }
+
+    #[inline(always)]
+    fn into_pixel(&self, acc: Self::Accumulator) -> GrayAlpha<T> {
+        if acc.a > fpN::ZERO {
+            let inv = fpN::ONE / acc.a;
+            GrayAlpha::new(T::from_float(acc.v * inv), T::from_float(acc.a))
+        } else {
+            let zero = T::from_float(fpN::ZERO);
+            GrayAlpha::new(zero, zero)
+        }
+    }
+}
+
+impl<F: ToFloat, T: ToFloat, const N: usize, const ALPHA: usize> PixelFormat
+    for formats::Channels<T, F, N, ALPHA>
+{
+    type InputPixel = [F; N];
+    type OutputPixel = formats::ChannelsPixel<T, N>;
+    type Accumulator = [fpN; N];
+
+    #[inline(always)]
+    fn new() -> Self::Accumulator {
+        // Compile-time guard: an out-of-range ALPHA would otherwise panic at
+        // runtime the first time `add`/`into_pixel` indexes with it.
+        const { assert!(ALPHA == formats::NO_ALPHA || ALPHA < N) };
+        [fpN::ZERO; N]
+    }
+
+    #[inline(always)]
+    fn add(&self, acc: &mut Self::Accumulator, inp: [F; N], coeff: fpN) {
+        if ALPHA == formats::NO_ALPHA {
+            for i in 0..N {
+                acc[i] += inp[i].to_float() * coeff;
+            }
+        } else {
+            let a_coeff = inp[ALPHA].to_float() * coeff;
+            for i in 0..N {
+                acc[i] += if i == ALPHA {
+                    a_coeff
+                } else {
+                    inp[i].to_float() * a_coeff
+                };
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn add_acc(acc: &mut Self::Accumulator, inp: Self::Accumulator, coeff: fpN) {
+        for i in 0..N {
+            acc[i] += inp[i] * coeff;
+        }
+    }
+
+    #[inline(always)]
+    fn into_pixel(&self, acc: Self::Accumulator) -> Self::OutputPixel {
+        let mut out = formats::ChannelsPixel([T::from_float(fpN::ZERO); N]);
+        if ALPHA == formats::NO_ALPHA {
+            for i in 0..N {
+                out[i] = T::from_float(acc[i]);
+            }
+        } else if acc[ALPHA] > fpN::ZERO {
+            let inv = fpN::ONE / acc[ALPHA];
+            for i in 0..N {
+                out[i] = if i == ALPHA {
+                    T::from_float(acc[ALPHA])
+                } else {
+                    T::from_float(acc[i] * inv)
+                };
+            }
+        }
+        out
+    }
+}
+
+impl<F: f::SrgbTransfer, T: f::SrgbTransfer> PixelFormat for formats::SrgbRgb<T, F> {
+    type InputPixel = RGB<F>;
+    type OutputPixel = RGB<T>;
+    type Accumulator = RGB<fpN>;
+
+    #[inline(always)]
+    fn new() -> Self::Accumulator {
+        RGB::new(fpN::ZERO, fpN::ZERO, fpN::ZERO)
+    }
+
+    #[inline(always)]
+    fn add(&self, acc: &mut Self::Accumulator, inp: RGB<F>, coeff: fpN) {
+        acc.r += inp.r.decode_linear() * coeff;
+        acc.g += inp.g.decode_linear() * coeff;
+        acc.b += inp.b.decode_linear() * coeff;
+    }
+
+    #[inline(always)]
+    fn add_acc(acc: &mut Self::Accumulator, inp: Self::Accumulator, coeff: fpN) {
+        acc.r += inp.r * coeff;
+        acc.g += inp.g * coeff;
+        acc.b += inp.b * coeff;
+    }
+
+    #[inline(always)]
+    fn into_pixel(&self, acc: Self::Accumulator) -> RGB<T> {
+        RGB {
+            r: T::encode_linear(acc.r),
+            g: T::encode_linear(acc.g),
+            b: T::encode_linear(acc.b),
+        }
+    }
+}
+
+impl<F: f::SrgbTransfer, T: f::SrgbTransfer> PixelFormat for formats::SrgbRgba<T, F> {
+    type InputPixel = RGBA<F>;
+    type OutputPixel = RGBA<T>;
+    type Accumulator = RGBA<fpN>;
+
+    #[inline(always)]
+    fn new() -> Self::Accumulator {
+        RGBA::new(fpN::ZERO, fpN::ZERO, fpN::ZERO, fpN::ZERO)
+    }
+
+    #[inline(always)]
+    fn add(&self, acc: &mut Self::Accumulator, inp: RGBA<F>, coeff: fpN) {
+        // Alpha is already linear; decode color to linear light, then
+        // premultiply, exactly like `RgbaPremultiply` but gamma-aware.
+        let a_coeff = inp.a.to_float() * coeff;
+        acc.r += inp.r.decode_linear() * a_coeff;
+        acc.g += inp.g.decode_linear() * a_coeff;
+        acc.b += inp.b.decode_linear() * a_coeff;
+        acc.a += a_coeff;
+    }
+
+    #[inline(always)]
+    fn add_acc(acc: &mut Self::Accumulator, inp: Self::Accumulator, coeff: fpN) {
+        acc.r += inp.r * coeff;
+        acc.g += inp.g * coeff;
+        acc.b += inp.b * coeff;
+        acc.a += inp.a * coeff;
+    }
+
+    #[inline(always)]
+    fn into_pixel(&self, acc: Self::Accumulator) -> RGBA<T> {
+        if acc.a > fpN::ZERO {
+            let inv = fpN::ONE / acc.a;
+            RGBA {
+                r: T::encode_linear(acc.r * inv),
+                g: T::encode_linear(acc.g * inv),
+                b: T::encode_linear(acc.b * inv),
+                a: T::from_float(acc.a),
+            }
+        } else {
+            let zero = T::from_float(fpN::ZERO);
+            RGBA::new(zero, zero, zero, zero)
+        }
+    }
+}
+
+impl<F: f::SrgbTransfer, T: f::SrgbTransfer> PixelFormat for formats::SrgbGray<F, T> {
+    type InputPixel = Gray<F>;
+    type OutputPixel = Gray<T>;
+    type Accumulator = Gray<fpN>;
+
+    #[inline(always)]
+    fn new() -> Self::Accumulator {
+        Gray::new(fpN::ZERO)
+    }
+
+    #[inline(always)]
+    fn add(&self, acc: &mut Self::Accumulator, inp: Gray<F>, coeff: fpN) {
+        acc.0 += inp.0.decode_linear() * coeff;
+    }
+
+    #[inline(always)]
+    fn add_acc(acc: &mut Self::Accumulator, inp: Self::Accumulator, coeff: fpN) {
+        acc.0 += inp.0 * coeff;
+    }
+
+    #[inline(always)]
+    fn into_pixel(&self, acc: Self::Accumulator) -> Gray<T> {
+        Gray::new(T::encode_linear(acc.0))
+    }
+}
+
+#[cfg(test)]
+mod pixelformat_tests {
+    use super::*;
+
+    #[test]
+    fn gray_alpha_premultiply_round_trips_full_weight_sample() {
+        let format = formats::GrayAlphaPremultiply::<u8, u8>::new();
+        let mut acc = <formats::GrayAlphaPremultiply<u8, u8> as PixelFormat>::new();
+        format.add(&mut acc, GrayAlpha::new(200u8, 128u8), 1.0);
+        let out = format.into_pixel(acc);
+        assert_eq!(out.a, 128);
+        // A single full-weight tap premultiplied then un-premultiplied by
+        // the same alpha should round-trip `v`, modulo 8-bit rounding.
+        assert!((out.v as i16 - 200).abs() <= 1, "out.v={}", out.v);
+    }
+
+    #[test]
+    fn gray_alpha_premultiply_zero_alpha_falls_back_to_transparent_zero() {
+        let format = formats::GrayAlphaPremultiply::<u8, u8>::new();
+        let mut acc = <formats::GrayAlphaPremultiply<u8, u8> as PixelFormat>::new();
+        format.add(&mut acc, GrayAlpha::new(200u8, 0u8), 1.0);
+        let out = format.into_pixel(acc);
+        assert_eq!(out.v, 0);
+        assert_eq!(out.a, 0);
+    }
+
+    #[test]
+    fn gray_alpha_straight_accumulates_channels_independently() {
+        let format = formats::GrayAlpha::<u8, u8>::new();
+        let mut acc = <formats::GrayAlpha<u8, u8> as PixelFormat>::new();
+        format.add(&mut acc, GrayAlpha::new(100u8, 50u8), 0.5);
+        format.add(&mut acc, GrayAlpha::new(100u8, 50u8), 0.5);
+        let out = format.into_pixel(acc);
+        assert_eq!(out.v, 100);
+        assert_eq!(out.a, 50);
+    }
+
+    #[test]
+    fn channels_no_alpha_straight_round_trip() {
+        type Fmt = formats::Channels<u8, u8, 4, { formats::NO_ALPHA }>;
+        let format = Fmt::new();
+        let mut acc = <Fmt as PixelFormat>::new();
+        format.add(&mut acc, [10u8, 20, 30, 40], 1.0);
+        let out = format.into_pixel(acc);
+        assert_eq!(out.0, [10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn channels_alpha_premultiplies_like_rgba() {
+        // 4 channels with channel 3 designated as alpha (mirrors RGBA's
+        // premultiply semantics, generalized to N channels).
+        type Fmt = formats::Channels<u8, u8, 4, 3>;
+        let format = Fmt::new();
+        let mut acc = <Fmt as PixelFormat>::new();
+        format.add(&mut acc, [200u8, 100, 50, 128], 1.0);
+        let out = format.into_pixel(acc);
+        assert_eq!(out[3], 128);
+        assert!((out[0] as i16 - 200).abs() <= 1, "out[0]={}", out[0]);
+        assert!((out[1] as i16 - 100).abs() <= 1, "out[1]={}", out[1]);
+    }
+
+    #[test]
+    fn channels_alpha_zero_falls_back_to_default() {
+        type Fmt = formats::Channels<u8, u8, 4, 3>;
+        let format = Fmt::new();
+        let mut acc = <Fmt as PixelFormat>::new();
+        format.add(&mut acc, [200u8, 100, 50, 0], 1.0);
+        let out = format.into_pixel(acc);
+        assert_eq!(out.0, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn channels_pixel_default_and_indexing() {
+        let mut pixel: formats::ChannelsPixel<u8, 4> = Default::default();
+        assert_eq!(pixel.0, [0, 0, 0, 0]);
+        pixel[2] = 42;
+        assert_eq!(pixel[2], 42);
+    }
+}
+
 use self::f::ToFloat;
 mod f {
     use super::fpN;
@@ -213,6 +581,74 @@ mod f {
         }
     }
 
+    impl ToFloat for i8 {
+        #[inline(always)]
+        fn to_float(self) -> fpN {
+            fpN::from(self)
+        }
+
+        #[inline(always)]
+        fn from_float(f: fpN) -> Self {
+            round_signed(f).as_()
+        }
+    }
+
+    impl ToFloat for i16 {
+        #[inline(always)]
+        fn to_float(self) -> fpN {
+            fpN::from_f32(self as f32)
+        }
+
+        #[inline(always)]
+        fn from_float(f: fpN) -> Self {
+            round_signed(f).as_()
+        }
+    }
+
+    /// `u32` samples exceed `f16`'s ~65504 max representable value, so under
+    /// the `fp16` accumulator (`cargo feature fp16`) values above that will
+    /// overflow the accumulator itself to infinity, not just the output — there is no
+    /// per-type promotion to a wider accumulator in this version. Stick to
+    /// the default `f32` accumulator (the non-`fp16` build) for 16-bit+
+    /// imagery such as medical or depth scans.
+    impl ToFloat for u32 {
+        #[inline(always)]
+        fn to_float(self) -> fpN {
+            fpN::from_f32(self as f32)
+        }
+
+        #[inline(always)]
+        fn from_float(f: fpN) -> Self {
+            let r: u32 = (f + fpN::from_f32(0.5)).as_();
+            r
+        }
+    }
+
+    /// See the `fp16` precision caveat on the `u32` impl above — it applies
+    /// here too.
+    impl ToFloat for i32 {
+        #[inline(always)]
+        fn to_float(self) -> fpN {
+            fpN::from_f32(self as f32)
+        }
+
+        #[inline(always)]
+        fn from_float(f: fpN) -> Self {
+            round_signed(f).as_()
+        }
+    }
+
+    /// Round half-away-from-zero, matching the unsigned impls' `+ 0.5`
+    /// rounding but symmetric around zero — needed because sharp filters
+    /// (Lanczos, Catmull-Rom) overshoot past the input range and can
+    /// otherwise push values across zero in the wrong direction before the
+    /// saturating cast. The `as` cast after this already saturates to the
+    /// target type's range.
+    #[inline(always)]
+    fn round_signed(f: fpN) -> fpN {
+        f + fpN::from_f32(f.to_f32().signum() * 0.5)
+    }
+
     impl ToFloat for fpN {
         #[inline(always)]
         fn to_float(self) -> fpN {
@@ -236,4 +672,223 @@ mod f {
             f.to_f64()
         }
     }
+
+    /// sRGB EOTF: decode an encoded channel in `[0, 1]` to linear light.
+    #[inline(always)]
+    fn srgb_eotf(c: f32) -> f32 {
+        if c <= 0.040_45 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    }
+
+    /// sRGB OETF (inverse EOTF): encode a linear-light channel in `[0, 1]`.
+    #[inline(always)]
+    fn srgb_oetf(l: f32) -> f32 {
+        if l <= 0.003_130_8 {
+            12.92 * l
+        } else {
+            1.055 * l.powf(1.0 / 2.4) - 0.055
+        }
+    }
+
+    /// Decode/encode the sRGB transfer function for pixel storage types used
+    /// by [`formats::SrgbRgb`](crate::formats::SrgbRgb) and friends.
+    ///
+    /// Values are kept on the same `0..=255`-equivalent scale as [`ToFloat`]
+    /// (not normalized to `0..1`) so the sRGB formats slot into the same
+    /// accumulate-then-finalize machinery as the linear ones.
+    pub trait SrgbTransfer: ToFloat {
+        /// Decode a stored (gamma-encoded) sample to linear light.
+        fn decode_linear(self) -> fpN;
+        /// Re-encode a linear-light accumulator value back to this type.
+        fn encode_linear(l: fpN) -> Self;
+    }
+
+    /// 256-entry sRGB decode LUT for `u8`, built once on first use so the
+    /// per-tap hot loop in `add()` is just a table lookup.
+    fn u8_decode_lut() -> &'static [fpN; 256] {
+        static LUT: std::sync::OnceLock<[fpN; 256]> = std::sync::OnceLock::new();
+        LUT.get_or_init(|| {
+            let mut table = [fpN::ZERO; 256];
+            let mut i = 0;
+            while i < 256 {
+                let linear = srgb_eotf(i as f32 / 255.0) * 255.0;
+                table[i] = fpN::from_f32(linear);
+                i += 1;
+            }
+            table
+        })
+    }
+
+    impl SrgbTransfer for u8 {
+        #[inline(always)]
+        fn decode_linear(self) -> fpN {
+            u8_decode_lut()[self as usize]
+        }
+
+        #[inline(always)]
+        fn encode_linear(l: fpN) -> Self {
+            let encoded = srgb_oetf((l.to_f32() / 255.0).clamp(0.0, 1.0)) * 255.0;
+            (encoded + 0.5).clamp(0.0, 255.0) as u8
+        }
+    }
+
+    impl SrgbTransfer for u16 {
+        #[inline(always)]
+        fn decode_linear(self) -> fpN {
+            fpN::from_f32(srgb_eotf(self as f32 / 65535.0) * 65535.0)
+        }
+
+        #[inline(always)]
+        fn encode_linear(l: fpN) -> Self {
+            let encoded = srgb_oetf((l.to_f32() / 65535.0).clamp(0.0, 1.0)) * 65535.0;
+            (encoded + 0.5).clamp(0.0, 65535.0) as u16
+        }
+    }
+
+    impl SrgbTransfer for fpN {
+        #[inline(always)]
+        fn decode_linear(self) -> fpN {
+            fpN::from_f32(srgb_eotf(self.to_f32() / 255.0) * 255.0)
+        }
+
+        #[inline(always)]
+        fn encode_linear(l: fpN) -> Self {
+            fpN::from_f32(srgb_oetf((l.to_f32() / 255.0).clamp(0.0, 1.0)) * 255.0)
+        }
+    }
+
+    impl SrgbTransfer for f64 {
+        #[inline(always)]
+        fn decode_linear(self) -> fpN {
+            fpN::from_f64((srgb_eotf(self as f32 / 255.0) * 255.0) as f64)
+        }
+
+        #[inline(always)]
+        fn encode_linear(l: fpN) -> Self {
+            (srgb_oetf((l.to_f32() / 255.0).clamp(0.0, 1.0)) * 255.0) as f64
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn srgb_u8_round_trips_within_one_step() {
+            for c in [0u8, 1, 16, 127, 128, 200, 254, 255] {
+                let round_tripped = u8::encode_linear(c.decode_linear());
+                let diff = (round_tripped as i16 - c as i16).abs();
+                assert!(diff <= 1, "c={c} round_tripped={round_tripped}");
+            }
+        }
+
+        #[test]
+        fn srgb_u8_endpoints_are_exact() {
+            assert_eq!(0u8.decode_linear().to_f32(), 0.0);
+            // 255 maps to 1.0 linear (scaled back up to 255).
+            assert!((255u8.decode_linear().to_f32() - 255.0).abs() < 0.01);
+            assert_eq!(u8::encode_linear(fpN::ZERO), 0);
+            assert_eq!(u8::encode_linear(fpN::from_f32(255.0)), 255);
+        }
+
+        #[test]
+        fn srgb_u8_decode_is_monotonic() {
+            let lut: Vec<f32> = (0..=255u16).map(|c| (c as u8).decode_linear().to_f32()).collect();
+            assert!(lut.windows(2).all(|w| w[1] >= w[0]));
+        }
+
+        #[test]
+        fn srgb_u16_round_trips_within_tolerance() {
+            for c in [0u16, 1, 300, 32768, 65534, 65535] {
+                let round_tripped = u16::encode_linear(c.decode_linear());
+                let diff = (round_tripped as i32 - c as i32).abs();
+                assert!(diff <= 16, "c={c} round_tripped={round_tripped}");
+            }
+        }
+
+        // `fpN`/`f64` carry samples on the same `0..=255`-equivalent scale
+        // as every other `SrgbTransfer` impl (not normalized to `0..1`),
+        // matching their plain pass-through `ToFloat` impls elsewhere in
+        // this file.
+        #[test]
+        fn srgb_fpn_round_trips_within_tolerance() {
+            for c in [0.0_f32, 1.0, 64.0, 127.5, 200.0, 255.0] {
+                let c = fpN::from_f32(c);
+                let round_tripped = fpN::encode_linear(c.decode_linear()).to_f32();
+                assert!(
+                    (round_tripped - c.to_f32()).abs() < 1.0,
+                    "c={:?} round_tripped={round_tripped}",
+                    c.to_f32()
+                );
+            }
+        }
+
+        #[test]
+        fn srgb_f64_round_trips_within_tolerance() {
+            for c in [0.0_f64, 1.0, 64.0, 127.5, 200.0, 255.0] {
+                let round_tripped = f64::encode_linear(c.decode_linear());
+                assert!(
+                    (round_tripped - c).abs() < 1.0,
+                    "c={c} round_tripped={round_tripped}"
+                );
+            }
+        }
+
+        #[test]
+        fn srgb_fpn_and_f64_endpoints_match_u8() {
+            // All `SrgbTransfer` impls share the same normalized curve, so
+            // the 0/255 endpoints should agree across storage types.
+            assert_eq!(fpN::decode_linear(fpN::ZERO).to_f32(), 0u8.decode_linear().to_f32());
+            assert!(
+                (fpN::decode_linear(fpN::from_f32(255.0)).to_f32() - 255u8.decode_linear().to_f32())
+                    .abs()
+                    < 0.01
+            );
+            assert_eq!(f64::decode_linear(0.0).to_f32(), 0u8.decode_linear().to_f32());
+            assert!(
+                (f64::decode_linear(255.0).to_f32() - 255u8.decode_linear().to_f32()).abs() < 0.01
+            );
+        }
+
+        #[test]
+        fn i8_round_half_away_from_zero_and_saturates() {
+            assert_eq!(i8::from_float(fpN::ZERO), 0);
+            assert_eq!(i8::from_float(fpN::from_f32(0.5)), 1);
+            assert_eq!(i8::from_float(fpN::from_f32(-0.5)), -1);
+            assert_eq!(i8::from_float(fpN::from_f32(1.0)), 1);
+            assert_eq!(i8::from_float(fpN::from_f32(-1.0)), -1);
+            // Lanczos/Catmull-Rom overshoot past the representable range
+            // must saturate, not wrap.
+            assert_eq!(i8::from_float(fpN::from_f32(1000.0)), i8::MAX);
+            assert_eq!(i8::from_float(fpN::from_f32(-1000.0)), i8::MIN);
+        }
+
+        #[test]
+        fn i16_round_half_away_from_zero_and_saturates() {
+            assert_eq!(i16::from_float(fpN::ZERO), 0);
+            assert_eq!(i16::from_float(fpN::from_f32(0.5)), 1);
+            assert_eq!(i16::from_float(fpN::from_f32(-0.5)), -1);
+            assert_eq!(i16::from_float(fpN::from_f32(1_000_000.0)), i16::MAX);
+            assert_eq!(i16::from_float(fpN::from_f32(-1_000_000.0)), i16::MIN);
+        }
+
+        #[test]
+        fn i32_round_half_away_from_zero_and_saturates() {
+            assert_eq!(i32::from_float(fpN::ZERO), 0);
+            assert_eq!(i32::from_float(fpN::from_f32(0.5)), 1);
+            assert_eq!(i32::from_float(fpN::from_f32(-0.5)), -1);
+        }
+
+        #[test]
+        fn u32_rounds_and_saturates_to_zero() {
+            assert_eq!(u32::from_float(fpN::ZERO), 0);
+            assert_eq!(u32::from_float(fpN::from_f32(0.5)), 1);
+            // Negative overshoot must saturate to 0, not wrap to a huge
+            // unsigned value.
+            assert_eq!(u32::from_float(fpN::from_f32(-1000.0)), 0);
+        }
+    }
 }
@@ -0,0 +1,156 @@
+//! x86/x86_64 SIMD fast path for the horizontal resize pass
+//! ([`PixelFormat::add_row`](crate::px::PixelFormat::add_row)) on 8-bit
+//! RGBA input, the hottest single case for ordinary image resizing.
+//!
+//! Status: **partial**. The original ask was a general vector-friendly
+//! redesign — a shared lane abstraction, explicit FMA batching across
+//! 4/8 pixels at once, and an `f16` lane path under the `fp16` feature —
+//! across `Rgb`/`Rgba`/`Gray`/`GrayAlpha`/`Channels`. What's here instead:
+//!
+//! - Only `formats::Rgba<_, u8>` has a SIMD override; `Rgb`, `Gray`,
+//!   `GrayAlpha`, the sRGB wrappers, and `Channels` all still run the
+//!   scalar default from [`PixelFormat::add_row`](crate::px::PixelFormat::add_row).
+//! - Only the horizontal pass (`add_row`) is covered; the vertical pass
+//!   (`add_acc_row`) has no SIMD override for any format.
+//! - [`add_row_rgba_u8_sse2`] vectorizes across one pixel's 4 channels per
+//!   SSE2 register, not across multiple pixels at once — there's no
+//!   shared lane abstraction multiple formats plug into.
+//! - There is no `f16`/`fp16` lane path; that configuration keeps the
+//!   portable scalar loop (see below).
+//!
+//! Widening this to the other formats, the vertical pass, and `fp16` is
+//! still open work.
+#![cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "fp16")))]
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use rgb::RGBA;
+
+/// Whether the SSE2 fast path in this module can be used on this CPU.
+#[inline]
+pub(crate) fn has_sse2() -> bool {
+    #[cfg(target_arch = "x86_64")]
+    {
+        true // SSE2 is part of the x86_64 baseline ISA.
+    }
+    #[cfg(target_arch = "x86")]
+    {
+        is_x86_feature_detected!("sse2")
+    }
+}
+
+/// Widen 4 packed `u8` RGBA pixels (16 bytes) into 4 `(r, g, b, a)` `f32x4`
+/// lanes, one vector per pixel, via the standard SSE2 byte -> word -> dword
+/// unpack ladder (no SSSE3 shuffle required).
+#[target_feature(enable = "sse2")]
+#[inline]
+unsafe fn widen_4_rgba_u8(bytes: __m128i) -> [__m128; 4] {
+    let zero = _mm_setzero_si128();
+    let lo16 = _mm_unpacklo_epi8(bytes, zero); // pixels 0,1 as u16 lanes
+    let hi16 = _mm_unpackhi_epi8(bytes, zero); // pixels 2,3 as u16 lanes
+    let p0 = _mm_unpacklo_epi16(lo16, zero);
+    let p1 = _mm_unpackhi_epi16(lo16, zero);
+    let p2 = _mm_unpacklo_epi16(hi16, zero);
+    let p3 = _mm_unpackhi_epi16(hi16, zero);
+    [
+        _mm_cvtepi32_ps(p0),
+        _mm_cvtepi32_ps(p1),
+        _mm_cvtepi32_ps(p2),
+        _mm_cvtepi32_ps(p3),
+    ]
+}
+
+/// Accumulate a row of 8-bit RGBA pixels weighted by a single coefficient,
+/// 4 pixels at a time.
+///
+/// # Safety
+/// Caller must have verified [`has_sse2`] and that `acc_row.len() ==
+/// inp_row.len()`.
+#[target_feature(enable = "sse2")]
+pub(crate) unsafe fn add_row_rgba_u8_sse2(
+    acc_row: &mut [RGBA<f32>],
+    inp_row: &[RGBA<u8>],
+    coeff: f32,
+) {
+    debug_assert_eq!(acc_row.len(), inp_row.len());
+    let coeff_v = _mm_set1_ps(coeff);
+    let len = inp_row.len();
+    let chunks = len / 4;
+
+    for chunk in 0..chunks {
+        let base = chunk * 4;
+        let bytes = _mm_loadu_si128(inp_row.as_ptr().add(base) as *const __m128i);
+        let px = widen_4_rgba_u8(bytes);
+        for (i, &pixel) in px.iter().enumerate() {
+            let acc_ptr = acc_row.as_mut_ptr().add(base + i) as *mut f32;
+            let acc = _mm_loadu_ps(acc_ptr);
+            let updated = _mm_add_ps(acc, _mm_mul_ps(pixel, coeff_v));
+            _mm_storeu_ps(acc_ptr, updated);
+        }
+    }
+
+    // Scalar tail for row lengths not divisible by 4.
+    for i in (chunks * 4)..len {
+        let inp = inp_row[i];
+        acc_row[i].r += inp.r as f32 * coeff;
+        acc_row[i].g += inp.g as f32 * coeff;
+        acc_row[i].b += inp.b as f32 * coeff;
+        acc_row[i].a += inp.a as f32 * coeff;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scalar_add_row(acc_row: &mut [RGBA<f32>], inp_row: &[RGBA<u8>], coeff: f32) {
+        for (acc, inp) in acc_row.iter_mut().zip(inp_row) {
+            acc.r += inp.r as f32 * coeff;
+            acc.g += inp.g as f32 * coeff;
+            acc.b += inp.b as f32 * coeff;
+            acc.a += inp.a as f32 * coeff;
+        }
+    }
+
+    fn test_row(len: usize) -> Vec<RGBA<u8>> {
+        (0..len)
+            .map(|i| {
+                let b = (i * 37) as u8;
+                RGBA::new(b, b.wrapping_add(1), b.wrapping_add(2), b.wrapping_add(3))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sse2_matches_scalar_for_various_row_lengths() {
+        if !has_sse2() {
+            // Nothing to verify on a CPU without SSE2 (shouldn't happen on
+            // x86_64, which guarantees it, but x86 targets may lack it).
+            return;
+        }
+
+        for len in [0usize, 1, 2, 3, 4, 5, 7, 8, 9, 13, 16, 17] {
+            let inp_row = test_row(len);
+            let coeff = 0.3_f32;
+
+            let mut simd_acc = vec![RGBA::new(0.0_f32, 0.0, 0.0, 0.0); len];
+            unsafe { add_row_rgba_u8_sse2(&mut simd_acc, &inp_row, coeff) };
+
+            let mut scalar_acc = vec![RGBA::new(0.0_f32, 0.0, 0.0, 0.0); len];
+            scalar_add_row(&mut scalar_acc, &inp_row, coeff);
+
+            for (i, (s, r)) in simd_acc.iter().zip(scalar_acc.iter()).enumerate() {
+                assert!(
+                    (s.r - r.r).abs() < 1e-5
+                        && (s.g - r.g).abs() < 1e-5
+                        && (s.b - r.b).abs() < 1e-5
+                        && (s.a - r.a).abs() < 1e-5,
+                    "row len={len} index={i}: simd={s:?} scalar={r:?}"
+                );
+            }
+        }
+    }
+}